@@ -1,6 +1,12 @@
+use std::collections::BinaryHeap;
 use std::collections::HashMap as Map;
 use std::collections::HashSet as Set;
+use std::collections::VecDeque;
+use std::cmp::Reverse;
 use std::hash::Hash;
+use std::io;
+use std::io::Write;
+use std::ops::Add;
 use std::rc::Rc;
 use std::iter;
 
@@ -16,6 +22,22 @@ macro_rules! set {
 pub type VertexIndex = usize;
 pub type EdgeIndex = usize;
 
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+macro_rules! impl_zero {
+    ($($t:ty),*) => {
+        $(impl Zero for $t {
+            fn zero() -> Self {
+                0 as $t
+            }
+        })*
+    }
+}
+
+impl_zero!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub struct Edge<E> {
     pub source: VertexIndex,
@@ -31,13 +53,19 @@ pub struct Vertex<V> {
 pub struct DirectedGraph<V, E> {
     vertex_to_index: Map<Rc<Vertex<V>>, VertexIndex>,
     index_to_vertex: Map<VertexIndex, Rc<Vertex<V>>>,
+    next_vertex_index: VertexIndex,
+    free_vertex_indices: Vec<VertexIndex>,
     edge_to_index: Map<Rc<Edge<E>>, EdgeIndex>,
     index_to_edge: Map<EdgeIndex, Rc<Edge<E>>>,
+    next_edge_index: EdgeIndex,
+    free_edge_indices: Vec<EdgeIndex>,
     edges_from: Map<VertexIndex, Set<EdgeIndex>>,
+    edges_to: Map<VertexIndex, Set<EdgeIndex>>,
     edges_between: Map<(VertexIndex, VertexIndex), Set<EdgeIndex>>,
+    by_data: Map<VertexIndex, Map<E, Set<EdgeIndex>>>,
 }
 
-impl<V, E> DirectedGraph<V, E> 
+impl<V, E> DirectedGraph<V, E>
 where
     V: Eq + Hash,
     E: Eq + Hash,
@@ -46,10 +74,16 @@ where
         DirectedGraph {
             vertex_to_index: Map::new(),
             index_to_vertex: Map::new(),
+            next_vertex_index: 0,
+            free_vertex_indices: Vec::new(),
             edge_to_index: Map::new(),
             index_to_edge: Map::new(),
+            next_edge_index: 0,
+            free_edge_indices: Vec::new(),
             edges_from: Map::new(),
+            edges_to: Map::new(),
             edges_between: Map::new(),
+            by_data: Map::new(),
         }
     }
 
@@ -57,7 +91,11 @@ where
         if let Some(&vertex_index) = self.vertex_to_index.get(&vertex) {
             vertex_index
         } else {
-            let vertex_index = self.vertex_to_index.len();
+            let vertex_index = self.free_vertex_indices.pop().unwrap_or_else(|| {
+                let vertex_index = self.next_vertex_index;
+                self.next_vertex_index += 1;
+                vertex_index
+            });
             let vertex_rc = Rc::new(vertex);
             self.vertex_to_index.insert(vertex_rc.clone(), vertex_index);
             self.index_to_vertex.insert(vertex_index, vertex_rc);
@@ -65,6 +103,24 @@ where
         }
     }
 
+    pub fn remove_vertex(&mut self, vertex_index: VertexIndex) {
+        let vertex_rc = self.index_to_vertex.remove(&vertex_index).expect("vertex index out of bounds");
+        self.vertex_to_index.remove(&vertex_rc);
+        let mut incident_edges = set![];
+        if let Some(edges_from) = self.edges_from.get(&vertex_index) {
+            incident_edges.extend(edges_from.iter().copied());
+        }
+        if let Some(edges_to) = self.edges_to.get(&vertex_index) {
+            incident_edges.extend(edges_to.iter().copied());
+        }
+        for edge_index in incident_edges {
+            self.remove_edge(edge_index);
+        }
+        self.edges_from.remove(&vertex_index);
+        self.edges_to.remove(&vertex_index);
+        self.free_vertex_indices.push(vertex_index);
+    }
+
     pub fn contains_vertex(&self, vertex: &Vertex<V>) -> Option<VertexIndex> {
         self.vertex_to_index.get(vertex).map(|&vertex_index| vertex_index)
     }
@@ -95,6 +151,247 @@ where
         }
     }
 
+    pub fn get_predecessors<'a>(&'a self, vertex_index: VertexIndex) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(edges_to) = self.edges_to.get(&vertex_index) {
+            Box::new(edges_to.iter().map(move |edge_index| self.index_to_edge.get(edge_index).unwrap().source))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn get_edges_to<'a>(&'a self, vertex_index: VertexIndex) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(edges_to) = self.edges_to.get(&vertex_index) {
+            Box::new(edges_to.iter().map(|&edge_index| edge_index))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn get_edges_from_with_data<'a>(&'a self, vertex_index: VertexIndex, data: &E) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(by_data) = self.by_data.get(&vertex_index).and_then(|by_data| by_data.get(data)) {
+            Box::new(by_data.iter().map(|&edge_index| edge_index))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn get_neighbors_with_data<'a>(&'a self, vertex_index: VertexIndex, data: &E) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(by_data) = self.by_data.get(&vertex_index).and_then(|by_data| by_data.get(data)) {
+            Box::new(by_data.iter().map(move |edge_index| self.index_to_edge.get(edge_index).unwrap().target))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn contains_edge(&self, edge: &Edge<E>) -> Option<EdgeIndex> {
+        self.edge_to_index.get(edge).map(|&edge_index| edge_index)
+    }
+
+    pub fn remove_edge(&mut self, edge_index: EdgeIndex) {
+        let edge_rc = self.index_to_edge.remove(&edge_index).expect("edge index out of bounds");
+        self.edge_to_index.remove(&edge_rc);
+        if let Some(edges_from) = self.edges_from.get_mut(&edge_rc.source) {
+            edges_from.remove(&edge_index);
+        }
+        if let Some(edges_to) = self.edges_to.get_mut(&edge_rc.target) {
+            edges_to.remove(&edge_index);
+        }
+        if let Some(edges_between) = self.edges_between.get_mut(&(edge_rc.source, edge_rc.target)) {
+            edges_between.remove(&edge_index);
+        }
+        if let Some(by_data) = self.by_data.get_mut(&edge_rc.source).and_then(|by_data| by_data.get_mut(&edge_rc.data)) {
+            by_data.remove(&edge_index);
+        }
+        self.free_edge_indices.push(edge_index);
+    }
+
+    pub fn get_edge(&self, edge_index: EdgeIndex) -> &Edge<E> {
+        self.index_to_edge.get(&edge_index).expect("edge index out of bounds")
+    }
+
+    pub fn get_edges_between<'a>(&'a self, source_vertex_index: VertexIndex, target_vertex_index: VertexIndex) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        if self.index_to_vertex.get(&source_vertex_index).is_none() {
+            panic!("source vertex index out of bounds");
+        }
+        if self.index_to_vertex.get(&target_vertex_index).is_none() {
+            panic!("target vertex index out of bounds");
+        }
+        if let Some(edges_between) = self.edges_between.get(&(source_vertex_index, target_vertex_index)) {
+            Box::new(edges_between.iter().map(|&edge_index| edge_index))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn vertices<'a>(&'a self) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
+        Box::new(self.index_to_vertex.keys().map(|&vertex_index| vertex_index))
+    }
+
+    pub fn edges<'a>(&'a self) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        Box::new(self.index_to_edge.keys().map(|&edge_index| edge_index))
+    }
+
+    pub fn bfs<'a>(&'a self, start: VertexIndex) -> Bfs<'a, V, E> {
+        Bfs::new(self, start)
+    }
+
+    pub fn dfs<'a>(&'a self, start: VertexIndex) -> Dfs<'a, V, E> {
+        Dfs::new(self, start)
+    }
+
+    pub fn dijkstra<F, W>(&self, source: VertexIndex, cost_fn: F) -> Map<VertexIndex, W>
+    where
+        F: Fn(&Edge<E>) -> W,
+        W: Copy + Ord + Add<Output = W> + Zero,
+    {
+        if self.index_to_vertex.get(&source).is_none() {
+            panic!("source vertex index out of bounds");
+        }
+        let mut dist: Map<VertexIndex, W> = Map::new();
+        dist.insert(source, W::zero());
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((W::zero(), source)));
+        while let Some(Reverse((vertex_dist, vertex_index))) = heap.pop() {
+            if vertex_dist > *dist.get(&vertex_index).unwrap() {
+                continue;
+            }
+            for edge_index in self.get_edges_from(vertex_index) {
+                let edge = self.get_edge(edge_index);
+                let next_dist = vertex_dist + cost_fn(edge);
+                if dist.get(&edge.target).map_or(true, |&current_dist| next_dist < current_dist) {
+                    dist.insert(edge.target, next_dist);
+                    heap.push(Reverse((next_dist, edge.target)));
+                }
+            }
+        }
+        dist
+    }
+
+    pub fn astar<F, H, W>(&self, source: VertexIndex, goal: VertexIndex, cost_fn: F, heuristic: H) -> Option<Vec<EdgeIndex>>
+    where
+        F: Fn(&Edge<E>) -> W,
+        H: Fn(VertexIndex) -> W,
+        W: Copy + Ord + Add<Output = W> + Zero,
+    {
+        if self.index_to_vertex.get(&source).is_none() {
+            panic!("source vertex index out of bounds");
+        }
+        if self.index_to_vertex.get(&goal).is_none() {
+            panic!("goal vertex index out of bounds");
+        }
+        let mut dist: Map<VertexIndex, W> = Map::new();
+        dist.insert(source, W::zero());
+        let mut predecessor: Map<VertexIndex, EdgeIndex> = Map::new();
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((heuristic(source), source)));
+        while let Some(Reverse((_, vertex_index))) = heap.pop() {
+            if vertex_index == goal {
+                let mut path = Vec::new();
+                let mut current = goal;
+                while let Some(&edge_index) = predecessor.get(&current) {
+                    path.push(edge_index);
+                    current = self.get_edge(edge_index).source;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let vertex_dist = *dist.get(&vertex_index).unwrap();
+            for edge_index in self.get_edges_from(vertex_index) {
+                let edge = self.get_edge(edge_index);
+                let next_dist = vertex_dist + cost_fn(edge);
+                if dist.get(&edge.target).map_or(true, |&current_dist| next_dist < current_dist) {
+                    dist.insert(edge.target, next_dist);
+                    predecessor.insert(edge.target, edge_index);
+                    heap.push(Reverse((next_dist + heuristic(edge.target), edge.target)));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn write_dot<W: Write>(&self, out: &mut W, vertex_label: impl Fn(&Vertex<V>) -> String, edge_label: impl Fn(&Edge<E>) -> String) -> io::Result<()> {
+        writeln!(out, "digraph {{")?;
+        for vertex_index in self.vertices() {
+            writeln!(out, "    {} [label=\"{}\"];", vertex_index, escape_dot_label(&vertex_label(self.get_vertex(vertex_index))))?;
+        }
+        for edge_index in self.edges() {
+            let edge = self.get_edge(edge_index);
+            writeln!(out, "    {} -> {} [label=\"{}\"];", edge.source, edge.target, escape_dot_label(&edge_label(edge)))?;
+        }
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    pub fn to_dot_string(&self, vertex_label: impl Fn(&Vertex<V>) -> String, edge_label: impl Fn(&Edge<E>) -> String) -> String {
+        let mut out = Vec::new();
+        self.write_dot(&mut out, vertex_label, edge_label).expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(out).expect("dot output is valid utf-8")
+    }
+}
+
+impl<V, E> DirectedGraph<V, E>
+where
+    V: Eq + Hash,
+    E: Clone + Eq + Hash,
+{
+    pub fn from_adjacency_matrix(matrix: &str, vertex_data: impl Fn(usize) -> V) -> DirectedGraph<V, E>
+    where
+        E: Default,
+    {
+        let rows: Vec<Vec<u8>> = matrix.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|entry| match entry {
+                        "0" => 0,
+                        "1" => 1,
+                        _ => panic!("adjacency matrix entries must be 0 or 1"),
+                    })
+                    .collect()
+            })
+            .collect();
+        let vertex_count = rows.len();
+        for row in &rows {
+            if row.len() != vertex_count {
+                panic!("adjacency matrix must be square");
+            }
+        }
+        let mut graph = DirectedGraph::new();
+        let vertex_indices: Vec<VertexIndex> = (0..vertex_count)
+            .map(|row_index| graph.add_vertex(Vertex { data: vertex_data(row_index) }))
+            .collect();
+        for (row_index, row) in rows.iter().enumerate() {
+            for (column_index, &entry) in row.iter().enumerate() {
+                if entry == 1 {
+                    graph.add_edge(Edge { source: vertex_indices[row_index], data: E::default(), target: vertex_indices[column_index] });
+                }
+            }
+        }
+        graph
+    }
+
+    pub fn from_edges<I: IntoIterator<Item = (V, E, V)>>(edges: I) -> DirectedGraph<V, E> {
+        let mut graph = DirectedGraph::new();
+        for (source, data, target) in edges {
+            let source_index = graph.add_vertex(Vertex { data: source });
+            let target_index = graph.add_vertex(Vertex { data: target });
+            graph.add_edge(Edge { source: source_index, data, target: target_index });
+        }
+        graph
+    }
+
     pub fn add_edge(&mut self, edge: Edge<E>) -> EdgeIndex {
         let edge_source = edge.source;
         let edge_target = edge.target;
@@ -107,7 +404,12 @@ where
         if let Some(&edge_index) = self.edge_to_index.get(&edge) {
             edge_index
         } else {
-            let edge_index = self.edge_to_index.len();
+            let edge_index = self.free_edge_indices.pop().unwrap_or_else(|| {
+                let edge_index = self.next_edge_index;
+                self.next_edge_index += 1;
+                edge_index
+            });
+            let edge_data = edge.data.clone();
             let edge_rc = Rc::new(edge);
             self.edge_to_index.insert(edge_rc.clone(), edge_index);
             self.index_to_edge.insert(edge_index, edge_rc);
@@ -116,43 +418,198 @@ where
             } else {
                 self.edges_from.insert(edge_source, set![edge_index]);
             }
+            if let Some(edges_to) = self.edges_to.get_mut(&edge_target) {
+                edges_to.insert(edge_index);
+            } else {
+                self.edges_to.insert(edge_target, set![edge_index]);
+            }
             if let Some(edges_between) = self.edges_between.get_mut(&(edge_source, edge_target)) {
                 edges_between.insert(edge_index);
             } else {
                 self.edges_between.insert((edge_source, edge_target), set![edge_index]);
             }
+            if let Some(by_data) = self.by_data.get_mut(&edge_source).and_then(|by_data| by_data.get_mut(&edge_data)) {
+                by_data.insert(edge_index);
+            } else {
+                self.by_data.entry(edge_source).or_insert_with(Map::new).insert(edge_data, set![edge_index]);
+            }
             edge_index
         }
     }
+}
 
-    pub fn contains_edge(&self, edge: &Edge<E>) -> Option<EdgeIndex> {
-        self.edge_to_index.get(edge).map(|&edge_index| edge_index)
+fn escape_dot_label(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    use serde::de::Error as DeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::hash::Hash;
+    use std::rc::Rc;
+    use super::{DirectedGraph, Edge, EdgeIndex, Map, Set, Vertex, VertexIndex};
+
+    #[derive(Serialize, Deserialize)]
+    struct WireVertex<V> {
+        index: VertexIndex,
+        data: V,
     }
 
-    pub fn get_edge(&self, edge_index: EdgeIndex) -> &Edge<E> {
-        self.index_to_edge.get(&edge_index).expect("edge index out of bounds")
+    #[derive(Serialize, Deserialize)]
+    struct WireEdge<E> {
+        index: EdgeIndex,
+        source: VertexIndex,
+        data: E,
+        target: VertexIndex,
     }
 
-    pub fn get_edges_between<'a>(&'a self, source_vertex_index: VertexIndex, target_vertex_index: VertexIndex) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
-        if self.index_to_vertex.get(&source_vertex_index).is_none() {
-            panic!("source vertex index out of bounds");
+    #[derive(Serialize, Deserialize)]
+    struct WireGraph<V, E> {
+        vertices: Vec<WireVertex<V>>,
+        edges: Vec<WireEdge<E>>,
+    }
+
+    impl<V, E> Serialize for DirectedGraph<V, E>
+    where
+        V: Clone + Eq + Hash + Serialize,
+        E: Clone + Eq + Hash + Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let wire = WireGraph {
+                vertices: self.index_to_vertex.iter()
+                    .map(|(&index, vertex)| WireVertex { index, data: vertex.data.clone() })
+                    .collect(),
+                edges: self.index_to_edge.iter()
+                    .map(|(&index, edge)| WireEdge { index, source: edge.source, data: edge.data.clone(), target: edge.target })
+                    .collect(),
+            };
+            wire.serialize(serializer)
         }
-        if self.index_to_vertex.get(&target_vertex_index).is_none() {
-            panic!("target vertex index out of bounds");
+    }
+
+    impl<'de, V, E> Deserialize<'de> for DirectedGraph<V, E>
+    where
+        V: Clone + Eq + Hash + Deserialize<'de>,
+        E: Clone + Eq + Hash + Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let wire = WireGraph::<V, E>::deserialize(deserializer)?;
+            let mut graph = DirectedGraph::new();
+            for wire_vertex in wire.vertices {
+                let vertex_rc = Rc::new(Vertex { data: wire_vertex.data });
+                graph.vertex_to_index.insert(vertex_rc.clone(), wire_vertex.index);
+                graph.index_to_vertex.insert(wire_vertex.index, vertex_rc);
+                graph.next_vertex_index = graph.next_vertex_index.max(wire_vertex.index + 1);
+            }
+            for wire_edge in wire.edges {
+                if !graph.index_to_vertex.contains_key(&wire_edge.source) || !graph.index_to_vertex.contains_key(&wire_edge.target) {
+                    return Err(D::Error::custom("edge references unknown vertex index"));
+                }
+                let edge_data = wire_edge.data.clone();
+                let edge_rc = Rc::new(Edge { source: wire_edge.source, data: wire_edge.data, target: wire_edge.target });
+                graph.edge_to_index.insert(edge_rc.clone(), wire_edge.index);
+                graph.index_to_edge.insert(wire_edge.index, edge_rc);
+                graph.edges_from.entry(wire_edge.source).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.edges_to.entry(wire_edge.target).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.edges_between.entry((wire_edge.source, wire_edge.target)).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.by_data.entry(wire_edge.source).or_insert_with(Map::new).entry(edge_data).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.next_edge_index = graph.next_edge_index.max(wire_edge.index + 1);
+            }
+            for vertex_index in 0..graph.next_vertex_index {
+                if !graph.index_to_vertex.contains_key(&vertex_index) {
+                    graph.free_vertex_indices.push(vertex_index);
+                }
+            }
+            for edge_index in 0..graph.next_edge_index {
+                if !graph.index_to_edge.contains_key(&edge_index) {
+                    graph.free_edge_indices.push(edge_index);
+                }
+            }
+            Ok(graph)
         }
-        if let Some(edges_between) = self.edges_between.get(&(source_vertex_index, target_vertex_index)) {
-            Box::new(edges_between.iter().map(|&edge_index| edge_index))
-        } else {
-            Box::new(iter::empty())
+    }
+}
+
+pub struct Bfs<'a, V, E> {
+    graph: &'a DirectedGraph<V, E>,
+    discovered: Set<VertexIndex>,
+    frontier: VecDeque<VertexIndex>,
+}
+
+impl<'a, V, E> Bfs<'a, V, E>
+where
+    V: Eq + Hash,
+    E: Eq + Hash,
+{
+    fn new(graph: &'a DirectedGraph<V, E>, start: VertexIndex) -> Bfs<'a, V, E> {
+        if graph.index_to_vertex.get(&start).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        Bfs {
+            graph,
+            discovered: set![start],
+            frontier: VecDeque::from(vec![start]),
         }
     }
+}
 
-    pub fn vertices<'a>(&'a self) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
-        Box::new(self.index_to_vertex.keys().map(|&vertex_index| vertex_index))
+impl<'a, V, E> Iterator for Bfs<'a, V, E>
+where
+    V: Eq + Hash,
+    E: Eq + Hash,
+{
+    type Item = VertexIndex;
+
+    fn next(&mut self) -> Option<VertexIndex> {
+        let vertex_index = self.frontier.pop_front()?;
+        for neighbor in self.graph.get_neighbors(vertex_index) {
+            if self.discovered.insert(neighbor) {
+                self.frontier.push_back(neighbor);
+            }
+        }
+        Some(vertex_index)
     }
+}
 
-    pub fn edges<'a>(&'a self) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
-        Box::new(self.index_to_edge.keys().map(|&edge_index| edge_index))
+pub struct Dfs<'a, V, E> {
+    graph: &'a DirectedGraph<V, E>,
+    discovered: Set<VertexIndex>,
+    frontier: Vec<VertexIndex>,
+}
+
+impl<'a, V, E> Dfs<'a, V, E>
+where
+    V: Eq + Hash,
+    E: Eq + Hash,
+{
+    fn new(graph: &'a DirectedGraph<V, E>, start: VertexIndex) -> Dfs<'a, V, E> {
+        if graph.index_to_vertex.get(&start).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        Dfs {
+            graph,
+            discovered: set![start],
+            frontier: vec![start],
+        }
+    }
+}
+
+impl<'a, V, E> Iterator for Dfs<'a, V, E>
+where
+    V: Eq + Hash,
+    E: Eq + Hash,
+{
+    type Item = VertexIndex;
+
+    fn next(&mut self) -> Option<VertexIndex> {
+        let vertex_index = self.frontier.pop()?;
+        for neighbor in self.graph.get_neighbors(vertex_index) {
+            if self.discovered.insert(neighbor) {
+                self.frontier.push(neighbor);
+            }
+        }
+        Some(vertex_index)
     }
 }
 
@@ -194,4 +651,154 @@ mod tests {
         assert_eq!(set![x1_a_x2, x1_b_x2, x1_a_x3], directed_graph.get_edges_from(x1).collect());
         assert_eq!(set![x1_a_x2, x1_b_x2], directed_graph.get_edges_between(x1, x2).collect());
     }
+
+    #[test]
+    fn test_4() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { data: "X3" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, data: 'a', target: x2 });
+        let x3_b_x2 = directed_graph.add_edge(Edge { source: x3, data: 'b', target: x2 });
+        assert_eq!(set![x1, x3], directed_graph.get_predecessors(x2).collect());
+        assert_eq!(set![x1_a_x2, x3_b_x2], directed_graph.get_edges_to(x2).collect());
+    }
+
+    #[test]
+    fn test_5() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, data: 'a', target: x2 });
+        directed_graph.remove_edge(x1_a_x2);
+        assert_eq!(None, directed_graph.contains_edge(&Edge { source: x1, data: 'a', target: x2 }));
+        assert_eq!(set![], directed_graph.get_edges_from(x1).collect());
+        assert_eq!(set![], directed_graph.get_edges_to(x2).collect());
+        let x1_b_x2 = directed_graph.add_edge(Edge { source: x1, data: 'b', target: x2 });
+        assert_eq!(x1_a_x2, x1_b_x2);
+    }
+
+    #[test]
+    fn test_6() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, data: 'a', target: x2 });
+        directed_graph.remove_vertex(x1);
+        assert_eq!(None, directed_graph.contains_vertex(&Vertex { data: "X1" }));
+        assert_eq!(None, directed_graph.contains_edge(&Edge { source: x1, data: 'a', target: x2 }));
+        let _ = x1_a_x2;
+        assert_eq!(set![], directed_graph.get_predecessors(x2).collect());
+        let x3 = directed_graph.add_vertex(Vertex { data: "X3" });
+        assert_eq!(x1, x3);
+    }
+
+    #[test]
+    fn test_7() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { data: "X3" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, data: 'a', target: x2 });
+        directed_graph.add_edge(Edge { source: x1, data: 'b', target: x2 });
+        let x1_a_x3 = directed_graph.add_edge(Edge { source: x1, data: 'a', target: x3 });
+        assert_eq!(set![x1_a_x2, x1_a_x3], directed_graph.get_edges_from_with_data(x1, &'a').collect());
+        assert_eq!(set![x2, x3], directed_graph.get_neighbors_with_data(x1, &'a').collect());
+    }
+
+    #[test]
+    fn test_8() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { data: "X3" });
+        let x4 = directed_graph.add_vertex(Vertex { data: "X4" });
+        directed_graph.add_edge(Edge { source: x1, data: 'a', target: x2 });
+        directed_graph.add_edge(Edge { source: x1, data: 'b', target: x3 });
+        directed_graph.add_edge(Edge { source: x2, data: 'c', target: x4 });
+        let bfs_order: Vec<_> = directed_graph.bfs(x1).collect();
+        assert_eq!(x1, bfs_order[0]);
+        assert_eq!(set![x1, x2, x3, x4], bfs_order.into_iter().collect());
+        let dfs_order: Vec<_> = directed_graph.dfs(x1).collect();
+        assert_eq!(x1, dfs_order[0]);
+        assert_eq!(set![x1, x2, x3, x4], dfs_order.into_iter().collect());
+        assert_eq!(vec![x3], directed_graph.bfs(x3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_9() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { data: "X3" });
+        directed_graph.add_edge(Edge { source: x1, data: 5, target: x2 });
+        directed_graph.add_edge(Edge { source: x1, data: 1, target: x3 });
+        directed_graph.add_edge(Edge { source: x3, data: 1, target: x2 });
+        let dist = directed_graph.dijkstra(x1, |edge| edge.data);
+        assert_eq!(Some(&0), dist.get(&x1));
+        assert_eq!(Some(&2), dist.get(&x2));
+        assert_eq!(Some(&1), dist.get(&x3));
+        let path = directed_graph.astar(x1, x2, |edge| edge.data, |_| 0).unwrap();
+        assert_eq!(2, path.len());
+        assert_eq!(x1, directed_graph.get_edge(path[0]).source);
+        assert_eq!(x2, directed_graph.get_edge(path[1]).target);
+    }
+
+    #[test]
+    fn test_10() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        directed_graph.add_edge(Edge { source: x1, data: "a\"b", target: x2 });
+        let dot = directed_graph.to_dot_string(|vertex| vertex.data.to_string(), |edge| edge.data.to_string());
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains(&format!("{} [label=\"X1\"];", x1)));
+        assert!(dot.contains(&format!("{} -> {} [label=\"a\\\"b\"];", x1, x2)));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_11() {
+        let mut directed_graph = DirectedGraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { data: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { data: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { data: "X3" });
+        directed_graph.remove_vertex(x2);
+        let x1_a_x3 = directed_graph.add_edge(Edge { source: x1, data: 'a', target: x3 });
+        let json = serde_json::to_string(&directed_graph).unwrap();
+        let mut round_tripped: DirectedGraph<&str, char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(&Vertex { data: "X1" }, round_tripped.get_vertex(x1));
+        assert_eq!(&Vertex { data: "X3" }, round_tripped.get_vertex(x3));
+        assert_eq!(&Edge { source: x1, data: 'a', target: x3 }, round_tripped.get_edge(x1_a_x3));
+        let x4 = round_tripped.add_vertex(Vertex { data: "X4" });
+        assert_eq!(x2, x4);
+    }
+
+    #[test]
+    fn test_12() {
+        let matrix = "0 1 0\n0 0 1\n0 0 0\n";
+        let directed_graph: DirectedGraph<usize, ()> = DirectedGraph::from_adjacency_matrix(matrix, |row_index| row_index);
+        assert_eq!(3, directed_graph.vertices().count());
+        assert_eq!(set![1], directed_graph.get_neighbors(0).collect());
+        assert_eq!(set![2], directed_graph.get_neighbors(1).collect());
+        assert_eq!(set![], directed_graph.get_neighbors(2).collect());
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacency matrix must be square")]
+    fn test_13() {
+        let matrix = "0 1\n0 0 0\n";
+        let _: DirectedGraph<usize, ()> = DirectedGraph::from_adjacency_matrix(matrix, |row_index| row_index);
+    }
+
+    #[test]
+    fn test_14() {
+        let directed_graph = DirectedGraph::from_edges(vec![("a", 1, "b"), ("b", 2, "c")]);
+        let a = directed_graph.contains_vertex(&Vertex { data: "a" }).unwrap();
+        let b = directed_graph.contains_vertex(&Vertex { data: "b" }).unwrap();
+        let c = directed_graph.contains_vertex(&Vertex { data: "c" }).unwrap();
+        assert_eq!(set![b], directed_graph.get_neighbors(a).collect());
+        assert_eq!(set![c], directed_graph.get_neighbors(b).collect());
+    }
 }
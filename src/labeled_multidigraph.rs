@@ -1,6 +1,9 @@
 use std::collections::HashMap as Map;
 use std::collections::HashSet as Set;
+use std::collections::VecDeque;
 use std::hash::Hash;
+use std::io;
+use std::io::Write;
 use std::rc::Rc;
 use std::iter;
 
@@ -31,13 +34,19 @@ pub struct Vertex<VertexLabel> {
 pub struct LabeledMultidigraph<VertexLabel, EdgeLabel> {
     vertex_to_index: Map<Rc<Vertex<VertexLabel>>, VertexIndex>,
     index_to_vertex: Map<VertexIndex, Rc<Vertex<VertexLabel>>>,
+    next_vertex_index: VertexIndex,
+    free_vertex_indices: Vec<VertexIndex>,
     edge_to_index: Map<Rc<Edge<EdgeLabel>>, EdgeIndex>,
     index_to_edge: Map<EdgeIndex, Rc<Edge<EdgeLabel>>>,
+    next_edge_index: EdgeIndex,
+    free_edge_indices: Vec<EdgeIndex>,
     edges_from: Map<VertexIndex, Set<EdgeIndex>>,
+    edges_to: Map<VertexIndex, Set<EdgeIndex>>,
     edges_between: Map<(VertexIndex, VertexIndex), Set<EdgeIndex>>,
+    by_label: Map<VertexIndex, Map<EdgeLabel, Set<EdgeIndex>>>,
 }
 
-impl<VertexLabel, EdgeLabel> LabeledMultidigraph<VertexLabel, EdgeLabel> 
+impl<VertexLabel, EdgeLabel> LabeledMultidigraph<VertexLabel, EdgeLabel>
 where
     VertexLabel: Eq + Hash,
     EdgeLabel: Eq + Hash,
@@ -46,10 +55,16 @@ where
         LabeledMultidigraph {
             vertex_to_index: Map::new(),
             index_to_vertex: Map::new(),
+            next_vertex_index: 0,
+            free_vertex_indices: Vec::new(),
             edge_to_index: Map::new(),
             index_to_edge: Map::new(),
+            next_edge_index: 0,
+            free_edge_indices: Vec::new(),
             edges_from: Map::new(),
+            edges_to: Map::new(),
             edges_between: Map::new(),
+            by_label: Map::new(),
         }
     }
 
@@ -57,7 +72,11 @@ where
         if let Some(&vertex_index) = self.vertex_to_index.get(&vertex) {
             vertex_index
         } else {
-            let vertex_index = self.vertex_to_index.len();
+            let vertex_index = self.free_vertex_indices.pop().unwrap_or_else(|| {
+                let vertex_index = self.next_vertex_index;
+                self.next_vertex_index += 1;
+                vertex_index
+            });
             let vertex_rc = Rc::new(vertex);
             self.vertex_to_index.insert(vertex_rc.clone(), vertex_index);
             self.index_to_vertex.insert(vertex_index, vertex_rc);
@@ -65,6 +84,24 @@ where
         }
     }
 
+    pub fn remove_vertex(&mut self, vertex_index: VertexIndex) {
+        let vertex_rc = self.index_to_vertex.remove(&vertex_index).expect("vertex index out of bounds");
+        self.vertex_to_index.remove(&vertex_rc);
+        let mut incident_edges = set![];
+        if let Some(edges_from) = self.edges_from.get(&vertex_index) {
+            incident_edges.extend(edges_from.iter().copied());
+        }
+        if let Some(edges_to) = self.edges_to.get(&vertex_index) {
+            incident_edges.extend(edges_to.iter().copied());
+        }
+        for edge_index in incident_edges {
+            self.remove_edge(edge_index);
+        }
+        self.edges_from.remove(&vertex_index);
+        self.edges_to.remove(&vertex_index);
+        self.free_vertex_indices.push(vertex_index);
+    }
+
     pub fn contains_vertex(&self, vertex: &Vertex<VertexLabel>) -> Option<VertexIndex> {
         self.vertex_to_index.get(vertex).map(|&vertex_index| vertex_index)
     }
@@ -95,6 +132,131 @@ where
         }
     }
 
+    pub fn get_predecessors<'a>(&'a self, vertex_index: VertexIndex) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(edges_to) = self.edges_to.get(&vertex_index) {
+            Box::new(edges_to.iter().map(move |edge_index| self.index_to_edge.get(edge_index).unwrap().source))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn get_edges_to<'a>(&'a self, vertex_index: VertexIndex) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(edges_to) = self.edges_to.get(&vertex_index) {
+            Box::new(edges_to.iter().map(|&edge_index| edge_index))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn get_edges_from_with_label<'a>(&'a self, vertex_index: VertexIndex, label: &EdgeLabel) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(by_label) = self.by_label.get(&vertex_index).and_then(|by_label| by_label.get(label)) {
+            Box::new(by_label.iter().map(|&edge_index| edge_index))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn get_neighbors_with_label<'a>(&'a self, vertex_index: VertexIndex, label: &EdgeLabel) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
+        if self.index_to_vertex.get(&vertex_index).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        if let Some(by_label) = self.by_label.get(&vertex_index).and_then(|by_label| by_label.get(label)) {
+            Box::new(by_label.iter().map(move |edge_index| self.index_to_edge.get(edge_index).unwrap().target))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn contains_edge(&self, edge: &Edge<EdgeLabel>) -> Option<EdgeIndex> {
+        self.edge_to_index.get(edge).map(|&edge_index| edge_index)
+    }
+
+    pub fn remove_edge(&mut self, edge_index: EdgeIndex) {
+        let edge_rc = self.index_to_edge.remove(&edge_index).expect("edge index out of bounds");
+        self.edge_to_index.remove(&edge_rc);
+        if let Some(edges_from) = self.edges_from.get_mut(&edge_rc.source) {
+            edges_from.remove(&edge_index);
+        }
+        if let Some(edges_to) = self.edges_to.get_mut(&edge_rc.target) {
+            edges_to.remove(&edge_index);
+        }
+        if let Some(edges_between) = self.edges_between.get_mut(&(edge_rc.source, edge_rc.target)) {
+            edges_between.remove(&edge_index);
+        }
+        if let Some(by_label) = self.by_label.get_mut(&edge_rc.source).and_then(|by_label| by_label.get_mut(&edge_rc.label)) {
+            by_label.remove(&edge_index);
+        }
+        self.free_edge_indices.push(edge_index);
+    }
+
+    pub fn get_edge(&self, edge_index: EdgeIndex) -> &Edge<EdgeLabel> {
+        self.index_to_edge.get(&edge_index).expect("edge index out of bounds")
+    }
+
+    pub fn get_edges_between<'a>(&'a self, source_vertex_index: VertexIndex, target_vertex_index: VertexIndex) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        if self.index_to_vertex.get(&source_vertex_index).is_none() {
+            panic!("source vertex index out of bounds");
+        }
+        if self.index_to_vertex.get(&target_vertex_index).is_none() {
+            panic!("target vertex index out of bounds");
+        }
+        if let Some(edges_between) = self.edges_between.get(&(source_vertex_index, target_vertex_index)) {
+            Box::new(edges_between.iter().map(|&edge_index| edge_index))
+        } else {
+            Box::new(iter::empty())
+        }
+    }
+
+    pub fn vertices<'a>(&'a self) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
+        Box::new(self.index_to_vertex.keys().map(|&vertex_index| vertex_index))
+    }
+
+    pub fn edges<'a>(&'a self) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
+        Box::new(self.index_to_edge.keys().map(|&edge_index| edge_index))
+    }
+
+    pub fn bfs<'a>(&'a self, start: VertexIndex) -> Bfs<'a, VertexLabel, EdgeLabel> {
+        Bfs::new(self, start)
+    }
+
+    pub fn dfs<'a>(&'a self, start: VertexIndex) -> Dfs<'a, VertexLabel, EdgeLabel> {
+        Dfs::new(self, start)
+    }
+
+    pub fn write_dot<W: Write>(&self, out: &mut W, vertex_label: impl Fn(&Vertex<VertexLabel>) -> String, edge_label: impl Fn(&Edge<EdgeLabel>) -> String) -> io::Result<()> {
+        writeln!(out, "digraph {{")?;
+        for vertex_index in self.vertices() {
+            writeln!(out, "    {} [label=\"{}\"];", vertex_index, escape_dot_label(&vertex_label(self.get_vertex(vertex_index))))?;
+        }
+        for edge_index in self.edges() {
+            let edge = self.get_edge(edge_index);
+            writeln!(out, "    {} -> {} [label=\"{}\"];", edge.source, edge.target, escape_dot_label(&edge_label(edge)))?;
+        }
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    pub fn to_dot_string(&self, vertex_label: impl Fn(&Vertex<VertexLabel>) -> String, edge_label: impl Fn(&Edge<EdgeLabel>) -> String) -> String {
+        let mut out = Vec::new();
+        self.write_dot(&mut out, vertex_label, edge_label).expect("writing to a Vec<u8> cannot fail");
+        String::from_utf8(out).expect("dot output is valid utf-8")
+    }
+}
+
+impl<VertexLabel, EdgeLabel> LabeledMultidigraph<VertexLabel, EdgeLabel>
+where
+    VertexLabel: Eq + Hash,
+    EdgeLabel: Clone + Eq + Hash,
+{
     pub fn add_edge(&mut self, edge: Edge<EdgeLabel>) -> EdgeIndex {
         let edge_source = edge.source;
         let edge_target = edge.target;
@@ -107,7 +269,12 @@ where
         if let Some(&edge_index) = self.edge_to_index.get(&edge) {
             edge_index
         } else {
-            let edge_index = self.edge_to_index.len();
+            let edge_index = self.free_edge_indices.pop().unwrap_or_else(|| {
+                let edge_index = self.next_edge_index;
+                self.next_edge_index += 1;
+                edge_index
+            });
+            let edge_label = edge.label.clone();
             let edge_rc = Rc::new(edge);
             self.edge_to_index.insert(edge_rc.clone(), edge_index);
             self.index_to_edge.insert(edge_index, edge_rc);
@@ -116,43 +283,198 @@ where
             } else {
                 self.edges_from.insert(edge_source, set![edge_index]);
             }
+            if let Some(edges_to) = self.edges_to.get_mut(&edge_target) {
+                edges_to.insert(edge_index);
+            } else {
+                self.edges_to.insert(edge_target, set![edge_index]);
+            }
             if let Some(edges_between) = self.edges_between.get_mut(&(edge_source, edge_target)) {
                 edges_between.insert(edge_index);
             } else {
                 self.edges_between.insert((edge_source, edge_target), set![edge_index]);
             }
+            if let Some(by_label) = self.by_label.get_mut(&edge_source).and_then(|by_label| by_label.get_mut(&edge_label)) {
+                by_label.insert(edge_index);
+            } else {
+                self.by_label.entry(edge_source).or_insert_with(Map::new).insert(edge_label, set![edge_index]);
+            }
             edge_index
         }
     }
+}
 
-    pub fn contains_edge(&self, edge: &Edge<EdgeLabel>) -> Option<EdgeIndex> {
-        self.edge_to_index.get(edge).map(|&edge_index| edge_index)
+fn escape_dot_label(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    use serde::de::Error as DeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::hash::Hash;
+    use std::rc::Rc;
+    use super::{Edge, EdgeIndex, LabeledMultidigraph, Map, Set, Vertex, VertexIndex};
+
+    #[derive(Serialize, Deserialize)]
+    struct WireVertex<VertexLabel> {
+        index: VertexIndex,
+        label: VertexLabel,
     }
 
-    pub fn get_edge(&self, edge_index: EdgeIndex) -> &Edge<EdgeLabel> {
-        self.index_to_edge.get(&edge_index).expect("edge index out of bounds")
+    #[derive(Serialize, Deserialize)]
+    struct WireEdge<EdgeLabel> {
+        index: EdgeIndex,
+        source: VertexIndex,
+        label: EdgeLabel,
+        target: VertexIndex,
     }
 
-    pub fn get_edges_between<'a>(&'a self, source_vertex_index: VertexIndex, target_vertex_index: VertexIndex) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
-        if self.index_to_vertex.get(&source_vertex_index).is_none() {
-            panic!("source vertex index out of bounds");
+    #[derive(Serialize, Deserialize)]
+    struct WireGraph<VertexLabel, EdgeLabel> {
+        vertices: Vec<WireVertex<VertexLabel>>,
+        edges: Vec<WireEdge<EdgeLabel>>,
+    }
+
+    impl<VertexLabel, EdgeLabel> Serialize for LabeledMultidigraph<VertexLabel, EdgeLabel>
+    where
+        VertexLabel: Clone + Eq + Hash + Serialize,
+        EdgeLabel: Clone + Eq + Hash + Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let wire = WireGraph {
+                vertices: self.index_to_vertex.iter()
+                    .map(|(&index, vertex)| WireVertex { index, label: vertex.label.clone() })
+                    .collect(),
+                edges: self.index_to_edge.iter()
+                    .map(|(&index, edge)| WireEdge { index, source: edge.source, label: edge.label.clone(), target: edge.target })
+                    .collect(),
+            };
+            wire.serialize(serializer)
         }
-        if self.index_to_vertex.get(&target_vertex_index).is_none() {
-            panic!("target vertex index out of bounds");
+    }
+
+    impl<'de, VertexLabel, EdgeLabel> Deserialize<'de> for LabeledMultidigraph<VertexLabel, EdgeLabel>
+    where
+        VertexLabel: Clone + Eq + Hash + Deserialize<'de>,
+        EdgeLabel: Clone + Eq + Hash + Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let wire = WireGraph::<VertexLabel, EdgeLabel>::deserialize(deserializer)?;
+            let mut graph = LabeledMultidigraph::new();
+            for wire_vertex in wire.vertices {
+                let vertex_rc = Rc::new(Vertex { label: wire_vertex.label });
+                graph.vertex_to_index.insert(vertex_rc.clone(), wire_vertex.index);
+                graph.index_to_vertex.insert(wire_vertex.index, vertex_rc);
+                graph.next_vertex_index = graph.next_vertex_index.max(wire_vertex.index + 1);
+            }
+            for wire_edge in wire.edges {
+                if !graph.index_to_vertex.contains_key(&wire_edge.source) || !graph.index_to_vertex.contains_key(&wire_edge.target) {
+                    return Err(D::Error::custom("edge references unknown vertex index"));
+                }
+                let edge_label = wire_edge.label.clone();
+                let edge_rc = Rc::new(Edge { source: wire_edge.source, label: wire_edge.label, target: wire_edge.target });
+                graph.edge_to_index.insert(edge_rc.clone(), wire_edge.index);
+                graph.index_to_edge.insert(wire_edge.index, edge_rc);
+                graph.edges_from.entry(wire_edge.source).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.edges_to.entry(wire_edge.target).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.edges_between.entry((wire_edge.source, wire_edge.target)).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.by_label.entry(wire_edge.source).or_insert_with(Map::new).entry(edge_label).or_insert_with(Set::new).insert(wire_edge.index);
+                graph.next_edge_index = graph.next_edge_index.max(wire_edge.index + 1);
+            }
+            for vertex_index in 0..graph.next_vertex_index {
+                if !graph.index_to_vertex.contains_key(&vertex_index) {
+                    graph.free_vertex_indices.push(vertex_index);
+                }
+            }
+            for edge_index in 0..graph.next_edge_index {
+                if !graph.index_to_edge.contains_key(&edge_index) {
+                    graph.free_edge_indices.push(edge_index);
+                }
+            }
+            Ok(graph)
         }
-        if let Some(edges_between) = self.edges_between.get(&(source_vertex_index, target_vertex_index)) {
-            Box::new(edges_between.iter().map(|&edge_index| edge_index))
-        } else {
-            Box::new(iter::empty())
+    }
+}
+
+pub struct Bfs<'a, VertexLabel, EdgeLabel> {
+    graph: &'a LabeledMultidigraph<VertexLabel, EdgeLabel>,
+    discovered: Set<VertexIndex>,
+    frontier: VecDeque<VertexIndex>,
+}
+
+impl<'a, VertexLabel, EdgeLabel> Bfs<'a, VertexLabel, EdgeLabel>
+where
+    VertexLabel: Eq + Hash,
+    EdgeLabel: Eq + Hash,
+{
+    fn new(graph: &'a LabeledMultidigraph<VertexLabel, EdgeLabel>, start: VertexIndex) -> Bfs<'a, VertexLabel, EdgeLabel> {
+        if graph.index_to_vertex.get(&start).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        Bfs {
+            graph,
+            discovered: set![start],
+            frontier: VecDeque::from(vec![start]),
         }
     }
+}
 
-    pub fn vertices<'a>(&'a self) -> Box<dyn Iterator<Item = VertexIndex> + 'a> {
-        Box::new(self.index_to_vertex.keys().map(|&vertex_index| vertex_index))
+impl<'a, VertexLabel, EdgeLabel> Iterator for Bfs<'a, VertexLabel, EdgeLabel>
+where
+    VertexLabel: Eq + Hash,
+    EdgeLabel: Eq + Hash,
+{
+    type Item = VertexIndex;
+
+    fn next(&mut self) -> Option<VertexIndex> {
+        let vertex_index = self.frontier.pop_front()?;
+        for neighbor in self.graph.get_neighbors(vertex_index) {
+            if self.discovered.insert(neighbor) {
+                self.frontier.push_back(neighbor);
+            }
+        }
+        Some(vertex_index)
     }
+}
 
-    pub fn edges<'a>(&'a self) -> Box<dyn Iterator<Item = EdgeIndex> + 'a> {
-        Box::new(self.index_to_edge.keys().map(|&edge_index| edge_index))
+pub struct Dfs<'a, VertexLabel, EdgeLabel> {
+    graph: &'a LabeledMultidigraph<VertexLabel, EdgeLabel>,
+    discovered: Set<VertexIndex>,
+    frontier: Vec<VertexIndex>,
+}
+
+impl<'a, VertexLabel, EdgeLabel> Dfs<'a, VertexLabel, EdgeLabel>
+where
+    VertexLabel: Eq + Hash,
+    EdgeLabel: Eq + Hash,
+{
+    fn new(graph: &'a LabeledMultidigraph<VertexLabel, EdgeLabel>, start: VertexIndex) -> Dfs<'a, VertexLabel, EdgeLabel> {
+        if graph.index_to_vertex.get(&start).is_none() {
+            panic!("vertex index out of bounds");
+        }
+        Dfs {
+            graph,
+            discovered: set![start],
+            frontier: vec![start],
+        }
+    }
+}
+
+impl<'a, VertexLabel, EdgeLabel> Iterator for Dfs<'a, VertexLabel, EdgeLabel>
+where
+    VertexLabel: Eq + Hash,
+    EdgeLabel: Eq + Hash,
+{
+    type Item = VertexIndex;
+
+    fn next(&mut self) -> Option<VertexIndex> {
+        let vertex_index = self.frontier.pop()?;
+        for neighbor in self.graph.get_neighbors(vertex_index) {
+            if self.discovered.insert(neighbor) {
+                self.frontier.push(neighbor);
+            }
+        }
+        Some(vertex_index)
     }
 }
 
@@ -194,4 +516,108 @@ mod tests {
         assert_eq!(set![x1_a_x2, x1_b_x2, x1_a_x3], directed_graph.get_edges_from(x1).collect());
         assert_eq!(set![x1_a_x2, x1_b_x2], directed_graph.get_edges_between(x1, x2).collect());
     }
+
+    #[test]
+    fn test_4() {
+        let mut directed_graph = LabeledMultidigraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { label: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { label: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { label: "X3" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, label: 'a', target: x2 });
+        let x3_b_x2 = directed_graph.add_edge(Edge { source: x3, label: 'b', target: x2 });
+        assert_eq!(set![x1, x3], directed_graph.get_predecessors(x2).collect());
+        assert_eq!(set![x1_a_x2, x3_b_x2], directed_graph.get_edges_to(x2).collect());
+    }
+
+    #[test]
+    fn test_5() {
+        let mut directed_graph = LabeledMultidigraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { label: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { label: "X2" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, label: 'a', target: x2 });
+        directed_graph.remove_edge(x1_a_x2);
+        assert_eq!(None, directed_graph.contains_edge(&Edge { source: x1, label: 'a', target: x2 }));
+        assert_eq!(set![], directed_graph.get_edges_from(x1).collect());
+        assert_eq!(set![], directed_graph.get_edges_to(x2).collect());
+        let x1_b_x2 = directed_graph.add_edge(Edge { source: x1, label: 'b', target: x2 });
+        assert_eq!(x1_a_x2, x1_b_x2);
+    }
+
+    #[test]
+    fn test_6() {
+        let mut directed_graph = LabeledMultidigraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { label: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { label: "X2" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, label: 'a', target: x2 });
+        directed_graph.remove_vertex(x1);
+        assert_eq!(None, directed_graph.contains_vertex(&Vertex { label: "X1" }));
+        assert_eq!(None, directed_graph.contains_edge(&Edge { source: x1, label: 'a', target: x2 }));
+        let _ = x1_a_x2;
+        assert_eq!(set![], directed_graph.get_predecessors(x2).collect());
+        let x3 = directed_graph.add_vertex(Vertex { label: "X3" });
+        assert_eq!(x1, x3);
+    }
+
+    #[test]
+    fn test_7() {
+        let mut directed_graph = LabeledMultidigraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { label: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { label: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { label: "X3" });
+        let x1_a_x2 = directed_graph.add_edge(Edge { source: x1, label: 'a', target: x2 });
+        directed_graph.add_edge(Edge { source: x1, label: 'b', target: x2 });
+        let x1_a_x3 = directed_graph.add_edge(Edge { source: x1, label: 'a', target: x3 });
+        assert_eq!(set![x1_a_x2, x1_a_x3], directed_graph.get_edges_from_with_label(x1, &'a').collect());
+        assert_eq!(set![x2, x3], directed_graph.get_neighbors_with_label(x1, &'a').collect());
+    }
+
+    #[test]
+    fn test_8() {
+        let mut directed_graph = LabeledMultidigraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { label: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { label: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { label: "X3" });
+        let x4 = directed_graph.add_vertex(Vertex { label: "X4" });
+        directed_graph.add_edge(Edge { source: x1, label: 'a', target: x2 });
+        directed_graph.add_edge(Edge { source: x1, label: 'b', target: x3 });
+        directed_graph.add_edge(Edge { source: x2, label: 'c', target: x4 });
+        let bfs_order: Vec<_> = directed_graph.bfs(x1).collect();
+        assert_eq!(x1, bfs_order[0]);
+        assert_eq!(set![x1, x2, x3, x4], bfs_order.into_iter().collect());
+        let dfs_order: Vec<_> = directed_graph.dfs(x1).collect();
+        assert_eq!(x1, dfs_order[0]);
+        assert_eq!(set![x1, x2, x3, x4], dfs_order.into_iter().collect());
+        assert_eq!(vec![x3], directed_graph.bfs(x3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_9() {
+        let mut directed_graph = LabeledMultidigraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { label: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { label: "X2" });
+        directed_graph.add_edge(Edge { source: x1, label: "a\"b", target: x2 });
+        let dot = directed_graph.to_dot_string(|vertex| vertex.label.to_string(), |edge| edge.label.to_string());
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains(&format!("{} [label=\"X1\"];", x1)));
+        assert!(dot.contains(&format!("{} -> {} [label=\"a\\\"b\"];", x1, x2)));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_10() {
+        let mut directed_graph = LabeledMultidigraph::new();
+        let x1 = directed_graph.add_vertex(Vertex { label: "X1" });
+        let x2 = directed_graph.add_vertex(Vertex { label: "X2" });
+        let x3 = directed_graph.add_vertex(Vertex { label: "X3" });
+        directed_graph.remove_vertex(x2);
+        let x1_a_x3 = directed_graph.add_edge(Edge { source: x1, label: 'a', target: x3 });
+        let json = serde_json::to_string(&directed_graph).unwrap();
+        let mut round_tripped: LabeledMultidigraph<&str, char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(&Vertex { label: "X1" }, round_tripped.get_vertex(x1));
+        assert_eq!(&Vertex { label: "X3" }, round_tripped.get_vertex(x3));
+        assert_eq!(&Edge { source: x1, label: 'a', target: x3 }, round_tripped.get_edge(x1_a_x3));
+        let x4 = round_tripped.add_vertex(Vertex { label: "X4" });
+        assert_eq!(x2, x4);
+    }
 }